@@ -1,18 +1,51 @@
+use std::collections::{HashSet, VecDeque};
+
 use super::{
     file_forbids_unsafe, has_unsafe_attributes, is_test_fn, is_test_mod,
     IncludeTests, RsFileMetrics,
 };
 
+use proc_macro2::{Span, TokenTree};
 use quote::quote;
+use serde::Serialize;
 use syn::{
-    visit, Expr, ExprUnary, ExprUnsafe, ImplItemMethod, ItemFn, ItemImpl,
-    ItemMod, ItemTrait, UnOp,
+    parse::Parser, punctuated::Punctuated, spanned::Spanned, visit, Block,
+    Expr, ExprCall, ExprMethodCall, ExprUnary, ExprUnsafe, ImplItemMethod,
+    ItemFn, ItemForeignMod, ItemImpl, ItemMod, ItemStatic, ItemTrait,
+    ItemUnion, Macro, Member, Token, Type, UnOp,
 };
 
+/// Upper bound on how deep `visit_macro` will recurse into re-parsed macro
+/// bodies, as a defensive guard against runaway recursion.
+const MAX_MACRO_RECURSION_DEPTH: usize = 8;
+
+/// Whether to also track the compiler's `unsafe_op_in_unsafe_fn`
+/// distinction inside `unsafe fn`/method bodies: operations written
+/// directly in the body are granted "implicitly" by the item's own
+/// `unsafe` keyword, while operations wrapped in an explicit inner
+/// `unsafe { }` block are "explicitly" scoped, the way code migrating
+/// towards the `unsafe_op_in_unsafe_fn` lint would write them.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum UnsafeOpInUnsafeFn {
+    Track,
+    Ignore,
+}
+
+impl Default for UnsafeOpInUnsafeFn {
+    /// Matches the scan's prior behavior, from before this mode existed.
+    fn default() -> Self {
+        UnsafeOpInUnsafeFn::Ignore
+    }
+}
+
 pub struct GeigerSynVisitor {
     /// Count unsafe usage inside tests
     include_tests: IncludeTests,
 
+    /// Whether to track the implicit-vs-explicit distinction inside
+    /// `unsafe fn`/method bodies.
+    unsafe_op_in_unsafe_fn: UnsafeOpInUnsafeFn,
+
     /// The resulting data from a single file scan.
     pub metrics: RsFileMetrics,
 
@@ -25,7 +58,54 @@ pub struct GeigerSynVisitor {
     /// when we leave the outmost unsafe scope and get back into a safe scope.
     unsafe_scopes: u32,
 
-    unsafe_stat: UnsafeStat,
+    /// Stack of the unsafe scopes currently being visited, innermost last.
+    /// A `Vec` rather than a single `UnsafeStat` because unsafe scopes can
+    /// nest (an `unsafe` block inside an `unsafe fn`, for instance), and
+    /// each nesting level needs its own tally to decide whether *it*, in
+    /// particular, was actually used.
+    unsafe_stat_stack: Vec<UnsafeStat>,
+
+    /// Names of `unsafe fn`s and `unsafe` methods seen so far in the file,
+    /// used to (heuristically) recognize calls to them later on.
+    unsafe_fn_names: HashSet<String>,
+
+    /// Names of `static mut` items seen so far in the file, used to
+    /// (heuristically) recognize accesses to them later on.
+    static_mut_names: HashSet<String>,
+
+    /// Names of fields declared on `union`s seen so far in the file, used to
+    /// (heuristically) recognize accesses to them later on.
+    union_field_names: HashSet<String>,
+
+    /// Names of functions declared inside `extern` blocks seen so far in the
+    /// file, used to (heuristically) recognize FFI calls later on.
+    extern_fn_names: HashSet<String>,
+
+    /// Module/impl-type/trait path leading to the item currently being
+    /// visited, seeded with the crate or file name via
+    /// [`GeigerSynVisitor::set_root_path`]. Used to build a fully-qualified
+    /// symbol (e.g. `mycrate::parser::Buffer::get_unchecked`) for every
+    /// unsafe item found, the same way item-path-based fuzz-target
+    /// selectors are built.
+    module_path: VecDeque<String>,
+
+    /// How many re-parsed macro bodies we are currently nested inside,
+    /// capped at [`MAX_MACRO_RECURSION_DEPTH`].
+    macro_depth: usize,
+
+    /// Every unsafe finding collected over this scan, in the order they
+    /// were closed. Kept on the visitor itself rather than on
+    /// [`RsFileMetrics`] since that struct is defined outside this crate
+    /// and isn't ours to add a field to here; callers can read it off the
+    /// visitor once the scan is done (e.g. via [`unsafe_findings_to_json`]).
+    pub unsafe_findings: Vec<UnsafeFinding>,
+
+    /// Whether `emit_finding` also prints the human-readable line to
+    /// stdout as each scope closes. Defaults to `true`, matching the
+    /// scan's prior behavior; callers that only want the structured/JSON
+    /// output (e.g. diffing findings between commits in CI) can opt out
+    /// via [`GeigerSynVisitor::set_print_findings`].
+    print_findings: bool,
 }
 
 #[derive(Debug)]
@@ -33,6 +113,74 @@ enum BlockType {
     Inner,
     Function,
     Method,
+    Impl,
+    Trait,
+    Macro,
+}
+
+/// Tally of the concrete unsafe operations observed inside a single unsafe
+/// scope, mirroring (on a best-effort, type-free basis) the categories the
+/// compiler's own unsafety checker distinguishes:
+///
+/// * `deref` - a raw-pointer dereference (`*expr`).
+/// * `unsafe_call` - a `Call`/`MethodCall` whose callee name matches the
+///   name of an `unsafe fn`/method seen earlier in the same file.
+/// * `static_mut` - a `Path` expression whose last segment matches the name
+///   of a `static mut` seen earlier in the same file.
+/// * `union_field` - a `Field` access whose member name matches a field
+///   name declared on a `union` seen earlier in the same file.
+/// * `inline_asm` - an `asm!`/`llvm_asm!` macro invocation.
+/// * `ffi_call` - a `Call` whose callee name matches the name of a function
+///   declared inside an `extern` block seen earlier in the same file.
+///
+/// `syn` performs no name/type resolution, so everything beyond `deref` is a
+/// syntactic approximation keyed off of names rather than types, and can
+/// both over- and under-count relative to what `rustc` would report.
+#[derive(Debug, Default)]
+struct UnsafeOpKinds {
+    deref: usize,
+    unsafe_call: usize,
+    static_mut: usize,
+    union_field: usize,
+    inline_asm: usize,
+    ffi_call: usize,
+}
+
+impl UnsafeOpKinds {
+    /// Total number of unsafe operations observed, across all kinds.
+    fn total(&self) -> usize {
+        self.deref
+            + self.unsafe_call
+            + self.static_mut
+            + self.union_field
+            + self.inline_asm
+            + self.ffi_call
+    }
+
+    /// Human readable list of the kinds that were actually observed, in a
+    /// fixed order, for inclusion in an [`UnsafeFinding`]'s `reasons`.
+    fn reasons(&self) -> Vec<&'static str> {
+        let mut reasons = Vec::new();
+        if self.deref > 0 {
+            reasons.push("Dereference Operation");
+        }
+        if self.unsafe_call > 0 {
+            reasons.push("Unsafe Call");
+        }
+        if self.static_mut > 0 {
+            reasons.push("Mutable Static Access");
+        }
+        if self.union_field > 0 {
+            reasons.push("Union Field Access");
+        }
+        if self.inline_asm > 0 {
+            reasons.push("Inline Assembly");
+        }
+        if self.ffi_call > 0 {
+            reasons.push("FFI Call");
+        }
+        reasons
+    }
 }
 
 struct UnsafeStat {
@@ -41,68 +189,412 @@ struct UnsafeStat {
     stmt: usize,
     block_type: BlockType,
     block: String,
-    has_deref: bool,
+    op_kinds: UnsafeOpKinds,
+    span: Span,
+
+    /// Fully-qualified module path of the item this scope belongs to, e.g.
+    /// `mycrate::parser::Buffer::get_unchecked` for an unsafe method, or
+    /// just the enclosing path for an anonymous `unsafe { }` block.
+    symbol: String,
+
+    /// Set once a genuinely unsafe operation has been attributed to this
+    /// scope (see [`GeigerSynVisitor::record_op_kind`]).
+    used: bool,
+
+    /// An explicit `unsafe { }` block nested directly inside another
+    /// unsafe scope grants nothing the enclosing scope didn't already
+    /// grant, so it is unnecessary regardless of `used`.
+    redundant: bool,
+
+    /// Count of unsafe operations attributed directly to this scope, i.e.
+    /// written outside of any further nested `unsafe { }` block. Only
+    /// populated for `Function`/`Method` scopes when
+    /// [`UnsafeOpInUnsafeFn::Track`] is in effect.
+    implicit_ops: usize,
+
+    /// Count of unsafe operations rolled up from explicit `unsafe { }`
+    /// blocks nested inside this scope, at any depth. Only populated for
+    /// `Function`/`Method` scopes when [`UnsafeOpInUnsafeFn::Track`] is in
+    /// effect.
+    explicit_ops: usize,
 }
 
 impl UnsafeStat {
-    fn stat(&mut self) {
-        // block ~ block_type ~ expr ~ stmt ~ reason
-        print!(
-            "{} ~ {:?} ~ {} ~ {}",
+    /// Turn the tally collected over this scope's lifetime into a
+    /// structured, serializable [`UnsafeFinding`].
+    fn into_finding(self) -> UnsafeFinding {
+        let start = self.span.start();
+        UnsafeFinding {
+            symbol: self.symbol,
+            block_type: format!("{:?}", self.block_type),
+            block: self.block,
+            line: start.line,
+            column: start.column,
+            expr_count: self.expr_curr - self.expr_prev,
+            stmt_count: self.stmt,
+            reasons: self
+                .op_kinds
+                .reasons()
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+            unnecessary: self.redundant || !self.used,
+            implicit_ops: self.implicit_ops,
+            explicit_ops: self.explicit_ops,
+        }
+    }
+}
+
+/// A single unsafe scope or occurrence found in a file, in a form suitable
+/// for machine consumption - e.g. diffing unsafe findings between commits
+/// in CI - rather than only the `~`-delimited text line this used to be
+/// printed as.
+#[derive(Debug, Clone, Serialize)]
+pub struct UnsafeFinding {
+    pub symbol: String,
+    pub block_type: String,
+    pub block: String,
+    pub line: usize,
+    pub column: usize,
+    pub expr_count: usize,
+    pub stmt_count: usize,
+    pub reasons: Vec<String>,
+    pub unnecessary: bool,
+    pub implicit_ops: usize,
+    pub explicit_ops: usize,
+}
+
+impl UnsafeFinding {
+    /// Build a finding for an unsafe item that is reported as a whole,
+    /// with no per-operation tallying of its own (an `unsafe impl`,
+    /// `unsafe trait`, or a macro body we couldn't attribute precisely) -
+    /// i.e. everything [`UnsafeStat::into_finding`] tracks beyond `symbol`,
+    /// `block_type`, `block`, `span`, and `reasons` is zeroed out.
+    fn structural(
+        symbol: String,
+        block_type: BlockType,
+        block: String,
+        span: Span,
+        reasons: Vec<String>,
+    ) -> Self {
+        let start = span.start();
+        UnsafeFinding {
+            symbol,
+            block_type: format!("{:?}", block_type),
+            block,
+            line: start.line,
+            column: start.column,
+            expr_count: 0,
+            stmt_count: 0,
+            reasons,
+            unnecessary: false,
+            implicit_ops: 0,
+            explicit_ops: 0,
+        }
+    }
+
+    /// Render this finding as the historical `~`-delimited human-readable
+    /// line (plus the unnecessary/implicit-explicit annotations), for the
+    /// default text report. Driven by the same data as [`to_json`].
+    ///
+    /// [`to_json`]: unsafe_findings_to_json
+    pub fn to_line(&self) -> String {
+        // symbol ~ block ~ block_type ~ expr ~ stmt ~ reasons
+        let mut line = format!(
+            "{} ~ {} ~ {} ~ {} ~ {}",
+            self.symbol,
             self.block,
             self.block_type,
-            self.expr_curr - self.expr_prev,
-            self.stmt
+            self.expr_count,
+            self.stmt_count
         );
-
-        if self.has_deref {
-            println!(" ~ Dereference Operation");
-            self.has_deref = false;
-        } else {
-            println!("");
+        if !self.reasons.is_empty() {
+            line.push_str(&format!(" ~ {}", self.reasons.join(", ")));
+        }
+        if self.unnecessary {
+            line.push_str("\n  -> unnecessary unsafe");
+        }
+        if self.implicit_ops > 0 || self.explicit_ops > 0 {
+            line.push_str(&format!(
+                "\n  -> unsafe_op_in_unsafe_fn: {} implicit, {} explicit",
+                self.implicit_ops, self.explicit_ops
+            ));
         }
+        line
+    }
+}
+
+/// Serialize a full set of findings (e.g. `visitor.unsafe_findings`)
+/// as JSON, so a CI pipeline can diff unsafe findings between commits or
+/// feed them into another tool.
+pub fn unsafe_findings_to_json(
+    findings: &[UnsafeFinding],
+) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(findings)
+}
+
+/// Best-effort check for an `asm!`/`llvm_asm!` macro invocation, based
+/// purely on the macro's path since `syn` does not resolve macro
+/// definitions.
+fn is_asm_macro(mac: &Macro) -> bool {
+    mac.path
+        .segments
+        .last()
+        .map(|segment| segment.ident == "asm" || segment.ident == "llvm_asm")
+        .unwrap_or(false)
+}
+
+/// Best-effort fallback for a macro body that doesn't parse as valid Rust
+/// (common for DSL macros): does the raw token stream contain an `unsafe`
+/// keyword anywhere, including nested inside delimited groups?
+fn tokens_contain_unsafe(tokens: proc_macro2::TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == "unsafe",
+        TokenTree::Group(group) => tokens_contain_unsafe(group.stream()),
+        TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+    })
+}
+
+/// Short name of an `impl`'s `Self` type, e.g. `Buffer` for `impl Buffer`
+/// or `impl<T> Wrapper<T>`. Falls back to the fully rendered type for
+/// anything that isn't a simple path type.
+fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_else(|| quote!(#ty).to_string()),
+        other => quote!(#other).to_string(),
     }
 }
 
 impl GeigerSynVisitor {
-    pub fn new(include_tests: IncludeTests) -> Self {
+    /// Construct a visitor with [`UnsafeOpInUnsafeFn::Ignore`], i.e. with
+    /// the same behavior this scan had before that mode existed. Prefer
+    /// [`GeigerSynVisitor::new`] at call sites that have a way to let the
+    /// user opt into [`UnsafeOpInUnsafeFn::Track`] (e.g. a CLI flag).
+    pub fn new_default(include_tests: IncludeTests) -> Self {
+        GeigerSynVisitor::new(include_tests, UnsafeOpInUnsafeFn::default())
+    }
+
+    pub fn new(
+        include_tests: IncludeTests,
+        unsafe_op_in_unsafe_fn: UnsafeOpInUnsafeFn,
+    ) -> Self {
         GeigerSynVisitor {
             include_tests,
+            unsafe_op_in_unsafe_fn,
             metrics: Default::default(),
             unsafe_scopes: 0,
-            unsafe_stat: UnsafeStat {
-                expr_prev: 0,
-                expr_curr: 0,
-                stmt: 0,
-                block_type: BlockType::Inner,
-                block: "".to_string(),
-                has_deref: false,
-            },
+            unsafe_stat_stack: Vec::new(),
+            unsafe_fn_names: HashSet::new(),
+            static_mut_names: HashSet::new(),
+            union_field_names: HashSet::new(),
+            extern_fn_names: HashSet::new(),
+            module_path: VecDeque::new(),
+            macro_depth: 0,
+            unsafe_findings: Vec::new(),
+            print_findings: true,
         }
     }
 
-    pub fn enter_unsafe_scope(&mut self) {
-        self.unsafe_scopes += 1;
+    /// Seed the path with the crate or file name this visitor is scanning,
+    /// so that reported unsafe items carry a fully-qualified symbol (e.g.
+    /// `mycrate::parser::Buffer::get_unchecked`) rather than a bare name.
+    pub fn set_root_path(&mut self, root: impl Into<String>) {
+        self.module_path.push_back(root.into());
     }
 
-    fn init_unsafe_stat(
+    /// Opt out of the default human-readable `println!` of each finding as
+    /// its scope closes, for callers that only want the structured
+    /// `unsafe_findings` / JSON output (e.g. a CI pipeline diffing
+    /// findings between commits).
+    pub fn set_print_findings(&mut self, print_findings: bool) {
+        self.print_findings = print_findings;
+    }
+
+    /// The module/impl/trait path leading to the item currently being
+    /// visited, joined with `::`.
+    fn current_path(&self) -> String {
+        self.module_path
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("::")
+    }
+
+    /// Fully-qualified symbol for an item named `name` declared at the
+    /// current path, e.g. `current_path::name`.
+    fn qualified_symbol(&self, name: &str) -> String {
+        let path = self.current_path();
+        if path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}::{}", path, name)
+        }
+    }
+
+    /// Push a new unsafe scope of `block_type` onto the stack. An explicit
+    /// `unsafe { }` block (`BlockType::Inner`) entered while already inside
+    /// another unsafe scope is redundant: the enclosing scope already
+    /// grants the unsafety, so this one adds nothing, independent of
+    /// whether it turns out to contain a genuinely unsafe operation.
+    pub fn enter_unsafe_scope(
         &mut self,
         block_type: BlockType,
         block: String,
         stmt: usize,
+        symbol: String,
+        span: Span,
     ) {
-        self.unsafe_stat.block_type = block_type;
-        self.unsafe_stat.block = block;
-        self.unsafe_stat.expr_prev =
-            self.metrics.counters.exprs.unsafe_ as usize;
-        self.unsafe_stat.stmt = stmt;
+        let redundant =
+            matches!(block_type, BlockType::Inner) && self.unsafe_scopes > 0;
+        let expr_prev = self.metrics.counters.exprs.unsafe_ as usize;
+        self.unsafe_scopes += 1;
+        self.unsafe_stat_stack.push(UnsafeStat {
+            expr_prev,
+            expr_curr: expr_prev,
+            stmt,
+            block_type,
+            block,
+            op_kinds: UnsafeOpKinds::default(),
+            span,
+            used: false,
+            redundant,
+            symbol,
+            implicit_ops: 0,
+            explicit_ops: 0,
+        });
     }
 
     pub fn exit_unsafe_scope(&mut self) {
         self.unsafe_scopes -= 1;
-        self.unsafe_stat.expr_curr =
-            self.metrics.counters.exprs.unsafe_ as usize;
-        self.unsafe_stat.stat();
+        let mut frame = self
+            .unsafe_stat_stack
+            .pop()
+            .expect("unsafe scope exited without a matching frame");
+        frame.expr_curr = self.metrics.counters.exprs.unsafe_ as usize;
+
+        // An explicit `unsafe { }` block's own unsafe operations all rely
+        // on the implicit grant of the enclosing `unsafe fn`/method, so
+        // they count as "explicit" ops of that enclosing scope. Walk up
+        // past any other `unsafe { }` blocks nested in between (e.g.
+        // `unsafe fn f() { unsafe { unsafe { *p } } }`) to find it, rather
+        // than only checking the immediate parent.
+        if self.unsafe_op_in_unsafe_fn == UnsafeOpInUnsafeFn::Track
+            && matches!(frame.block_type, BlockType::Inner)
+        {
+            if let Some(ancestor) =
+                self.unsafe_stat_stack.iter_mut().rev().find(|frame| {
+                    matches!(
+                        frame.block_type,
+                        BlockType::Function | BlockType::Method
+                    )
+                })
+            {
+                ancestor.explicit_ops += frame.op_kinds.total();
+            }
+        }
+
+        let finding = frame.into_finding();
+        self.emit_finding(finding);
+    }
+
+    /// Accumulate a finding into `self.unsafe_findings` for machine
+    /// consumption (e.g. [`unsafe_findings_to_json`]), and, unless
+    /// [`set_print_findings`](GeigerSynVisitor::set_print_findings) has
+    /// opted out, also print it in the default human-readable text format.
+    fn emit_finding(&mut self, finding: UnsafeFinding) {
+        if self.print_findings {
+            println!("{}", finding.to_line());
+        }
+        self.unsafe_findings.push(finding);
+    }
+
+    /// Mark the innermost unsafe scope as used, and fold `mark` into its
+    /// [`UnsafeOpKinds`] tally. An operation is always attributed to the
+    /// scope that is innermost *at the point the operation is visited* -
+    /// e.g. an operation written directly in an `unsafe fn` body, outside
+    /// any nested `unsafe { }` block, marks the function's own scope as
+    /// used rather than some block that hasn't been entered yet.
+    ///
+    /// A redundant nested `unsafe { }` block (one directly inside another
+    /// unsafe scope) grants nothing of its own, so an operation it contains
+    /// really only satisfies the nearest *non-redundant* enclosing scope.
+    /// That ancestor is credited as used too, so it isn't falsely reported
+    /// as unnecessary just because the operation happened to be written
+    /// inside a redundant inner block.
+    fn record_op_kind(&mut self, mark: impl FnOnce(&mut UnsafeOpKinds)) {
+        let track_unsafe_op_in_unsafe_fn =
+            self.unsafe_op_in_unsafe_fn == UnsafeOpInUnsafeFn::Track;
+        let len = self.unsafe_stat_stack.len();
+        if len == 0 {
+            return;
+        }
+        let innermost_redundant = {
+            let frame = &mut self.unsafe_stat_stack[len - 1];
+            mark(&mut frame.op_kinds);
+            frame.used = true;
+            if track_unsafe_op_in_unsafe_fn
+                && matches!(
+                    frame.block_type,
+                    BlockType::Function | BlockType::Method
+                )
+            {
+                frame.implicit_ops += 1;
+            }
+            frame.redundant
+        };
+        if innermost_redundant {
+            if let Some(ancestor) = self.unsafe_stat_stack[..len - 1]
+                .iter_mut()
+                .rev()
+                .find(|frame| !frame.redundant)
+            {
+                ancestor.used = true;
+            }
+        }
+    }
+
+    /// Classify `expr` as one (or more) of the [`UnsafeOpKinds`] and record
+    /// it on the innermost unsafe scope. Only called while inside an
+    /// unsafe scope.
+    fn record_unsafe_op_kind(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Call(ExprCall { func, .. }) => {
+                if let Expr::Path(path) = func.as_ref() {
+                    if let Some(segment) = path.path.segments.last() {
+                        let name = segment.ident.to_string();
+                        if self.unsafe_fn_names.contains(&name) {
+                            self.record_op_kind(|k| k.unsafe_call += 1);
+                        }
+                        if self.extern_fn_names.contains(&name) {
+                            self.record_op_kind(|k| k.ffi_call += 1);
+                        }
+                    }
+                }
+            }
+            Expr::MethodCall(ExprMethodCall { method, .. }) => {
+                if self.unsafe_fn_names.contains(&method.to_string()) {
+                    self.record_op_kind(|k| k.unsafe_call += 1);
+                }
+            }
+            Expr::Field(expr_field) => {
+                if let Member::Named(ident) = &expr_field.member {
+                    if self.union_field_names.contains(&ident.to_string()) {
+                        self.record_op_kind(|k| k.union_field += 1);
+                    }
+                }
+            }
+            Expr::Macro(expr_macro) => {
+                if is_asm_macro(&expr_macro.mac) {
+                    self.record_op_kind(|k| k.inline_asm += 1);
+                }
+            }
+            _ => {}
+        }
     }
 }
 
@@ -120,16 +612,20 @@ impl<'ast> visit::Visit<'ast> for GeigerSynVisitor {
         let unsafe_fn =
             item_fn.sig.unsafety.is_some() || has_unsafe_attributes(item_fn);
         if unsafe_fn {
-            self.init_unsafe_stat(
+            self.unsafe_fn_names.insert(item_fn.sig.ident.to_string());
+            let symbol =
+                self.qualified_symbol(&item_fn.sig.ident.to_string());
+            self.enter_unsafe_scope(
                 BlockType::Function,
                 quote!(#item_fn).to_string(),
                 item_fn.block.stmts.len(),
+                symbol,
+                item_fn.span(),
             );
-            self.enter_unsafe_scope();
         }
         self.metrics.counters.functions.count(unsafe_fn);
         visit::visit_item_fn(self, item_fn);
-        if item_fn.sig.unsafety.is_some() {
+        if unsafe_fn {
             self.exit_unsafe_scope()
         }
     }
@@ -138,11 +634,31 @@ impl<'ast> visit::Visit<'ast> for GeigerSynVisitor {
         // Total number of expressions of any type
         match i {
             Expr::Unsafe(i) => {
-                self.enter_unsafe_scope();
+                self.enter_unsafe_scope(
+                    BlockType::Inner,
+                    quote!(#i).to_string(),
+                    i.block.stmts.len(),
+                    self.current_path(),
+                    i.span(),
+                );
                 self.visit_expr_unsafe(i);
                 self.exit_unsafe_scope();
             }
-            Expr::Path(_) | Expr::Lit(_) => {
+            Expr::Path(expr_path) => {
+                if self.unsafe_scopes > 0 {
+                    if let Some(segment) = expr_path.path.segments.last() {
+                        if self
+                            .static_mut_names
+                            .contains(&segment.ident.to_string())
+                        {
+                            self.record_op_kind(|k| k.static_mut += 1);
+                        }
+                    }
+                }
+                // Do not count. The expression `f(x)` should count as one
+                // expression, not three.
+            }
+            Expr::Lit(_) => {
                 // Do not count. The expression `f(x)` should count as one
                 // expression, not three.
             }
@@ -153,17 +669,15 @@ impl<'ast> visit::Visit<'ast> for GeigerSynVisitor {
                 //     println!("{:#?}", other);
                 // }
                 self.metrics.counters.exprs.count(self.unsafe_scopes > 0);
+                if self.unsafe_scopes > 0 {
+                    self.record_unsafe_op_kind(other);
+                }
                 visit::visit_expr(self, other);
             }
         }
     }
 
     fn visit_expr_unsafe(&mut self, i: &ExprUnsafe) {
-        self.init_unsafe_stat(
-            BlockType::Inner,
-            quote!(#i).to_string(),
-            i.block.stmts.len(),
-        );
         for stmt in &i.block.stmts {
             self.visit_stmt(stmt);
         }
@@ -172,7 +686,7 @@ impl<'ast> visit::Visit<'ast> for GeigerSynVisitor {
     fn visit_expr_unary(&mut self, i: &ExprUnary) {
         if self.unsafe_scopes > 0 {
             if let UnOp::Deref(_) = i.op {
-                self.unsafe_stat.has_deref = true;
+                self.record_op_kind(|k| k.deref += 1);
             }
         }
         visit::visit_expr_unary(self, i);
@@ -182,13 +696,27 @@ impl<'ast> visit::Visit<'ast> for GeigerSynVisitor {
         if IncludeTests::No == self.include_tests && is_test_mod(i) {
             return;
         }
+        self.module_path.push_back(i.ident.to_string());
         visit::visit_item_mod(self, i);
+        self.module_path.pop_back();
     }
 
     fn visit_item_impl(&mut self, i: &ItemImpl) {
         // unsafe trait impl's
         self.metrics.counters.item_impls.count(i.unsafety.is_some());
+        let self_ty = type_name(&i.self_ty);
+        if i.unsafety.is_some() {
+            self.emit_finding(UnsafeFinding::structural(
+                self.qualified_symbol(&self_ty),
+                BlockType::Impl,
+                quote!(#i).to_string(),
+                i.span(),
+                Vec::new(),
+            ));
+        }
+        self.module_path.push_back(self_ty);
         visit::visit_item_impl(self, i);
+        self.module_path.pop_back();
     }
 
     fn visit_item_trait(&mut self, i: &ItemTrait) {
@@ -197,17 +725,58 @@ impl<'ast> visit::Visit<'ast> for GeigerSynVisitor {
             .counters
             .item_traits
             .count(i.unsafety.is_some());
+        let trait_name = i.ident.to_string();
+        if i.unsafety.is_some() {
+            self.emit_finding(UnsafeFinding::structural(
+                self.qualified_symbol(&trait_name),
+                BlockType::Trait,
+                quote!(#i).to_string(),
+                i.span(),
+                Vec::new(),
+            ));
+        }
+        self.module_path.push_back(trait_name);
         visit::visit_item_trait(self, i);
+        self.module_path.pop_back();
+    }
+
+    fn visit_item_static(&mut self, i: &ItemStatic) {
+        if i.mutability.is_some() {
+            self.static_mut_names.insert(i.ident.to_string());
+        }
+        visit::visit_item_static(self, i);
+    }
+
+    fn visit_item_union(&mut self, i: &ItemUnion) {
+        for field in &i.fields.named {
+            if let Some(ident) = &field.ident {
+                self.union_field_names.insert(ident.to_string());
+            }
+        }
+        visit::visit_item_union(self, i);
+    }
+
+    fn visit_item_foreign_mod(&mut self, i: &ItemForeignMod) {
+        for item in &i.items {
+            if let syn::ForeignItem::Fn(foreign_fn) = item {
+                self.extern_fn_names
+                    .insert(foreign_fn.sig.ident.to_string());
+            }
+        }
+        visit::visit_item_foreign_mod(self, i);
     }
 
     fn visit_impl_item_method(&mut self, i: &ImplItemMethod) {
         if i.sig.unsafety.is_some() {
-            self.init_unsafe_stat(
+            self.unsafe_fn_names.insert(i.sig.ident.to_string());
+            let symbol = self.qualified_symbol(&i.sig.ident.to_string());
+            self.enter_unsafe_scope(
                 BlockType::Method,
                 quote!(#i).to_string(),
                 i.block.stmts.len(),
+                symbol,
+                i.span(),
             );
-            self.enter_unsafe_scope();
         }
         self.metrics
             .counters
@@ -219,8 +788,394 @@ impl<'ast> visit::Visit<'ast> for GeigerSynVisitor {
         }
     }
 
-    // TODO: Visit macros.
-    //
-    // TODO: Figure out if there are other visit methods that should be
-    // implemented here.
+    // Function-like macro invocations (`foo!(...)`), wherever they appear -
+    // as an expression, a statement, or an item - all route through this
+    // single method, since `mac: Macro` is a field on `ExprMacro`,
+    // `ItemMacro`, and friends alike.
+    fn visit_macro(&mut self, i: &Macro) {
+        if is_asm_macro(i) {
+            // Already detected structurally, see `record_unsafe_op_kind`.
+            return;
+        }
+        if self.macro_depth >= MAX_MACRO_RECURSION_DEPTH {
+            return;
+        }
+        self.macro_depth += 1;
+
+        let tokens = i.tokens.clone();
+        if let Ok(block) = syn::parse2::<Block>(tokens.clone()) {
+            self.visit_block(&block);
+        } else if let Ok(stmts) =
+            Block::parse_within.parse2(tokens.clone())
+        {
+            for stmt in &stmts {
+                self.visit_stmt(stmt);
+            }
+        } else if let Ok(expr) = syn::parse2::<Expr>(tokens.clone()) {
+            self.visit_expr(&expr);
+        } else if let Ok(exprs) =
+            Punctuated::<Expr, Token![,]>::parse_terminated
+                .parse2(tokens.clone())
+        {
+            // Most real macro calls (`println!`, `format!`, `assert_eq!`,
+            // `vec![a, b, c]`, ...) are a comma-separated argument list,
+            // which parses as none of the three forms above.
+            for expr in &exprs {
+                self.visit_expr(expr);
+            }
+        } else if tokens_contain_unsafe(tokens) {
+            // We couldn't parse the macro body as Rust (common for DSL
+            // macros), but it does mention `unsafe` somewhere inside, so
+            // flag it as unsafe we can't otherwise account for.
+            self.emit_finding(UnsafeFinding::structural(
+                self.current_path(),
+                BlockType::Macro,
+                quote!(#i).to_string(),
+                i.span(),
+                vec!["Unattributed (macro body could not be parsed as Rust)"
+                    .to_string()],
+            ));
+        }
+
+        self.macro_depth -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::visit::Visit;
+
+    fn scan(src: &str, root: &str) -> Vec<UnsafeFinding> {
+        let file = syn::parse_str::<syn::File>(src).unwrap();
+        let mut visitor = GeigerSynVisitor::new_default(IncludeTests::No);
+        visitor.set_root_path(root);
+        visitor.visit_file(&file);
+        visitor.unsafe_findings
+    }
+
+    #[test]
+    fn redundant_nested_unsafe_block_is_flagged_but_fn_is_not() {
+        let findings = scan(
+            r#"
+                unsafe fn foo(p: *const i32) -> i32 {
+                    unsafe { *p }
+                }
+            "#,
+            "mycrate",
+        );
+
+        assert_eq!(findings.len(), 2);
+
+        let inner = &findings[0];
+        assert_eq!(inner.block_type, "Inner");
+        assert!(
+            inner.unnecessary,
+            "a nested unsafe block directly inside another unsafe scope \
+             grants nothing of its own and must be flagged"
+        );
+
+        let foo = &findings[1];
+        assert_eq!(foo.block_type, "Function");
+        assert!(
+            !foo.unnecessary,
+            "the deref inside the redundant nested block still satisfies \
+             foo's own unsafe grant, so foo must not be flagged too"
+        );
+    }
+
+    #[test]
+    fn macro_comma_separated_args_are_scanned_for_unsafe_ops() {
+        let findings = scan(
+            r#"
+                unsafe fn get(p: *const i32) -> i32 {
+                    println!("{:?}", *p);
+                    0
+                }
+            "#,
+            "mycrate",
+        );
+
+        assert_eq!(findings.len(), 1);
+        assert!(
+            findings[0]
+                .reasons
+                .iter()
+                .any(|r| r == "Dereference Operation"),
+            "a deref passed as one of several comma-separated macro \
+             arguments must still be found: {:?}",
+            findings[0].reasons
+        );
+    }
+
+    #[test]
+    fn macro_block_style_body_is_scanned_for_unsafe_ops() {
+        let findings = scan(
+            r#"
+                macro_rules! do_it {
+                    ($e:expr) => {{ $e }};
+                }
+
+                unsafe fn get(p: *const i32) -> i32 {
+                    do_it!({ *p })
+                }
+            "#,
+            "mycrate",
+        );
+
+        assert_eq!(findings.len(), 1);
+        assert!(
+            findings[0]
+                .reasons
+                .iter()
+                .any(|r| r == "Dereference Operation"),
+            "a deref inside a brace-delimited, statement-style macro call \
+             must still be found: {:?}",
+            findings[0].reasons
+        );
+    }
+
+    #[test]
+    fn macro_dsl_body_falls_back_to_unattributed_unsafe() {
+        let findings = scan(
+            r#"
+                fn run() {
+                    some_dsl! { unsafe blah blah not actually rust }
+                }
+            "#,
+            "mycrate",
+        );
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].block_type, "Macro");
+        assert!(findings[0]
+            .reasons
+            .iter()
+            .any(|r| r.contains("could not be parsed as Rust")));
+    }
+
+    #[test]
+    fn classifies_call_to_a_previously_declared_unsafe_fn() {
+        let findings = scan(
+            r#"
+                unsafe fn helper() {}
+
+                unsafe fn caller() {
+                    helper();
+                }
+            "#,
+            "mycrate",
+        );
+
+        let caller = findings
+            .iter()
+            .find(|f| f.symbol == "mycrate::caller")
+            .unwrap();
+        assert!(caller.reasons.iter().any(|r| r == "Unsafe Call"));
+    }
+
+    #[test]
+    fn classifies_mutable_static_access() {
+        let findings = scan(
+            r#"
+                static mut COUNTER: i32 = 0;
+
+                unsafe fn bump() {
+                    COUNTER;
+                }
+            "#,
+            "mycrate",
+        );
+
+        let bump = findings
+            .iter()
+            .find(|f| f.symbol == "mycrate::bump")
+            .unwrap();
+        assert!(bump.reasons.iter().any(|r| r == "Mutable Static Access"));
+    }
+
+    #[test]
+    fn classifies_union_field_access() {
+        let findings = scan(
+            r#"
+                union U {
+                    a: i32,
+                    b: f32,
+                }
+
+                unsafe fn read_union(u: U) -> i32 {
+                    u.a
+                }
+            "#,
+            "mycrate",
+        );
+
+        let read_union = findings
+            .iter()
+            .find(|f| f.symbol == "mycrate::read_union")
+            .unwrap();
+        assert!(read_union
+            .reasons
+            .iter()
+            .any(|r| r == "Union Field Access"));
+    }
+
+    #[test]
+    fn classifies_ffi_call() {
+        let findings = scan(
+            r#"
+                extern "C" {
+                    fn external_fn();
+                }
+
+                unsafe fn call_ffi() {
+                    external_fn();
+                }
+            "#,
+            "mycrate",
+        );
+
+        let call_ffi = findings
+            .iter()
+            .find(|f| f.symbol == "mycrate::call_ffi")
+            .unwrap();
+        assert!(call_ffi.reasons.iter().any(|r| r == "FFI Call"));
+    }
+
+    #[test]
+    fn classifies_inline_asm() {
+        let findings = scan(
+            r#"
+                unsafe fn use_asm() {
+                    asm!("nop");
+                }
+            "#,
+            "mycrate",
+        );
+
+        let use_asm = findings
+            .iter()
+            .find(|f| f.symbol == "mycrate::use_asm")
+            .unwrap();
+        assert!(use_asm.reasons.iter().any(|r| r == "Inline Assembly"));
+    }
+
+    #[test]
+    fn track_mode_tallies_implicit_and_explicit_ops_for_a_single_nested_block()
+    {
+        let file = syn::parse_str::<syn::File>(
+            r#"
+                unsafe fn single(p: *const i32, q: *const i32) {
+                    *p;
+                    unsafe {
+                        *q;
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let mut visitor = GeigerSynVisitor::new(
+            IncludeTests::No,
+            UnsafeOpInUnsafeFn::Track,
+        );
+        visitor.visit_file(&file);
+        let findings = visitor.unsafe_findings;
+
+        assert_eq!(findings.len(), 2);
+        let single = findings
+            .iter()
+            .find(|f| f.block_type == "Function")
+            .unwrap();
+        assert_eq!(single.implicit_ops, 1, "the bare `*p` is implicit");
+        assert_eq!(
+            single.explicit_ops, 1,
+            "the `*q` inside the explicit block is explicit"
+        );
+    }
+
+    #[test]
+    fn track_mode_rolls_explicit_ops_up_through_doubly_nested_blocks() {
+        let file = syn::parse_str::<syn::File>(
+            r#"
+                unsafe fn double(p: *const i32, q: *const i32) {
+                    *p;
+                    unsafe {
+                        unsafe {
+                            *q;
+                        }
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let mut visitor = GeigerSynVisitor::new(
+            IncludeTests::No,
+            UnsafeOpInUnsafeFn::Track,
+        );
+        visitor.visit_file(&file);
+        let findings = visitor.unsafe_findings;
+
+        assert_eq!(findings.len(), 3);
+        let double = findings
+            .iter()
+            .find(|f| f.block_type == "Function")
+            .unwrap();
+        assert_eq!(double.implicit_ops, 1, "the bare `*p` is implicit");
+        assert_eq!(
+            double.explicit_ops, 1,
+            "the doubly-nested `*q` must be credited to the fn exactly \
+             once, not dropped and not double-counted"
+        );
+    }
+
+    #[test]
+    fn set_print_findings_false_still_collects_structured_findings() {
+        let file =
+            syn::parse_str::<syn::File>("unsafe fn foo() {}").unwrap();
+        let mut visitor = GeigerSynVisitor::new_default(IncludeTests::No);
+        visitor.set_print_findings(false);
+        visitor.visit_file(&file);
+
+        assert_eq!(visitor.unsafe_findings.len(), 1);
+    }
+
+    #[test]
+    fn unsafe_findings_to_json_serializes_the_expected_fields() {
+        let findings = scan(
+            r#"
+                unsafe fn foo() {}
+            "#,
+            "mycrate",
+        );
+
+        let json = unsafe_findings_to_json(&findings).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value[0]["symbol"], "mycrate::foo");
+        assert_eq!(value[0]["block_type"], "Function");
+        assert_eq!(value[0]["unnecessary"], true);
+    }
+
+    #[test]
+    fn qualified_symbol_includes_module_and_impl_path() {
+        let findings = scan(
+            r#"
+                mod a {
+                    mod b {
+                        struct S;
+                        impl S {
+                            unsafe fn get_unchecked(&self) -> i32 { 0 }
+                        }
+                    }
+                }
+            "#,
+            "mycrate",
+        );
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(
+            findings[0].symbol,
+            "mycrate::a::b::S::get_unchecked"
+        );
+    }
 }